@@ -1,115 +1,238 @@
+use clap::Parser;
 use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
 use std::fs;
+use std::io::{self, Read};
+use std::num::ParseIntError;
 use std::ops::Add;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+
+/// Find where two or more wires cross.
+#[derive(Parser)]
+struct Cli {
+    /// Path to the wire definitions; reads from stdin when omitted.
+    input: Option<PathBuf>,
+
+    /// Report the minimal combined signal-step intersection instead of the closest one.
+    #[arg(short = 's', long = "signal-distance")]
+    signal_distance: bool,
+}
 
 fn main() {
-    let text = fs::read_to_string("input.txt").unwrap();
-    let wires = parse_wires(text);
-    println!(
-        "Closest intersection: {:?}",
-        find_closest_intersection(&wires[0], &wires[1])
-    );
+    let cli = Cli::parse();
 
-    println!(
-        "Intersection with minimal steps requires {} steps",
-        find_minimal_step_intersection(&wires[0], &wires[1]).unwrap()
-    );
+    let text = read_input(cli.input.as_deref()).unwrap_or_else(|err| fail(&err.to_string()));
+    let wires = parse_wires(&text).unwrap_or_else(|err| fail(&err.to_string()));
+
+    if wires.len() < 2 {
+        fail("input must contain at least two wires");
+    }
+
+    let wire_refs: Vec<&[Segment]> = wires.iter().map(Vec::as_slice).collect();
+
+    if cli.signal_distance {
+        let steps = find_minimal_step_intersection_all(&wire_refs)
+            .unwrap_or_else(|| fail("wires never cross"));
+        println!("{}", steps);
+    } else {
+        let point = find_closest_intersection_all(&wire_refs)
+            .unwrap_or_else(|| fail("wires never cross"));
+        println!("{}", point.distance_from_origin());
+    }
+}
+
+fn read_input(path: Option<&Path>) -> io::Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            Ok(text)
+        }
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {}", message);
+    process::exit(1);
 }
 
-fn parse_wires(text: String) -> Vec<Wire> {
-    // Read moves
-    let moves_of_wires: Vec<Vec<Move>> = text
-        .trim()
-        .split('\n') // First split -- on two wires
+/// Why a wire definition failed to parse. Only `UnknownDirection` names the offending character;
+/// the other variants do not say which wire or move failed.
+#[derive(Debug)]
+enum ParseError {
+    Empty,
+    MissingDistance,
+    InvalidDistance(ParseIntError),
+    UnknownDirection(char),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "wire definition or move is empty"),
+            ParseError::MissingDistance => write!(f, "move is missing a distance"),
+            ParseError::InvalidDistance(err) => write!(f, "invalid distance: {}", err),
+            ParseError::UnknownDirection(chr) => write!(f, "unknown direction '{}'", chr),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_wires(text: &str) -> Result<Vec<Wire>, ParseError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    text.split('\n') // First split -- on wires
         .map(|wire_text| {
-            wire_text
+            let moves = wire_text
                 .trim()
                 .split(',')
-                .map(|string| string.into())
-                .collect()
+                .map(str::parse)
+                .collect::<Result<Vec<Move>, ParseError>>()?;
+            Ok(moves_to_segments(&moves))
         })
-        .collect();
+        .collect()
+}
 
+/// Walk a wire's moves once, turning each one into a `Segment` that already knows its own
+/// bounds and the accumulated step count along it, instead of materializing every lattice
+/// point the wire passes through.
+fn moves_to_segments(moves: &[Move]) -> Wire {
     let origin = Point { x: 0, y: 0 };
+    let mut current = origin;
+    let mut steps = 0;
 
-    // Calculate every point of each wire
-    let mut wires: Vec<Wire> = moves_of_wires
+    moves
         .iter()
-        .map(|wire_moves| {
-            // Every wire starts at origin
-            let mut current = origin;
-            let mut before = origin;
-
-            wire_moves
-                .iter()
-                .map(|&move_| {
-                    let mut points = vec![];
-                    // Calculate the end of a segment
-                    before = current;
-                    current = current + move_;
-
-                    // Store points of this segment
-                    points.extend(all_points_between(before, current));
-                    points.push(current);
-
-                    // Return all points of this siegment
-                    points
-                })
-                // Collect points of segments in a flat vector of all points of a wire
-                .flatten()
-                .collect()
+        .map(|&move_| {
+            let next = current + move_;
+            let segment = Segment::new(current, next, steps);
+            steps += move_.distance;
+            current = next;
+            segment
         })
-        .collect();
-
-    // Add origin to all of the wires
-    for wire in &mut wires {
-        wire.insert(0, origin);
-    }
+        .collect()
+}
 
-    wires
-}
-
-fn all_points_between(first: Point, second: Point) -> Vec<Point> {
-    let mut points = Vec::new();
-
-    // Which way to go along x and along y
-    let step = Point {
-        x: match second.x.cmp(&first.x) {
-            Ordering::Greater => 1,
-            Ordering::Equal => 0,
-            Ordering::Less => -1,
-        },
-        y: match second.y.cmp(&first.y) {
-            Ordering::Greater => 1,
-            Ordering::Equal => 0,
-            Ordering::Less => -1,
-        },
-    };
-
-    assert!(
-        step.x == 0 || step.y == 0,
-        "Two points should lie on the same axis: either X or Y coordinates are equal"
-    );
+type Wire = Vec<Segment>;
+
+/// A straight piece of a wire, aligned to either the row or the column axis. Bounds are kept
+/// normalized (`col_start <= col_end`, `row_start <= row_end`) so a collision check is a plain
+/// range comparison; `steps_dir` records whether walking the segment in the direction of
+/// increasing coordinate adds to (+1) or subtracts from (-1) the accumulated step count, with
+/// `steps_start` holding the step count at the lower-bound endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment {
+    Horizontal {
+        row: i32,
+        col_start: i32,
+        col_end: i32,
+        steps_start: u32,
+        steps_dir: i32,
+    },
+    Vertical {
+        col: i32,
+        row_start: i32,
+        row_end: i32,
+        steps_start: u32,
+        steps_dir: i32,
+    },
+}
 
-    if (second.x - first.x).abs() == 1 || (second.y - first.y).abs() == 1 {
-        return vec![];
+impl Segment {
+    /// Build a segment from the two endpoints of a single move, given the wire's accumulated
+    /// step count before the move started.
+    fn new(start: Point, end: Point, steps_before: u32) -> Segment {
+        if start.y == end.y {
+            let (col_start, col_end, steps_start, steps_dir) = if start.x <= end.x {
+                (start.x, end.x, steps_before, 1)
+            } else {
+                (end.x, start.x, steps_before + (start.x - end.x) as u32, -1)
+            };
+            Segment::Horizontal {
+                row: start.y,
+                col_start,
+                col_end,
+                steps_start,
+                steps_dir,
+            }
+        } else {
+            let (row_start, row_end, steps_start, steps_dir) = if start.y <= end.y {
+                (start.y, end.y, steps_before, 1)
+            } else {
+                (end.y, start.y, steps_before + (start.y - end.y) as u32, -1)
+            };
+            Segment::Vertical {
+                col: start.x,
+                row_start,
+                row_end,
+                steps_start,
+                steps_dir,
+            }
+        }
     }
 
-    let mut current = first;
+    /// Where this segment crosses `other`, if anywhere. Two parallel segments never collide.
+    fn collision(&self, other: &Segment) -> Option<Point> {
+        match (self, other) {
+            (Segment::Horizontal { .. }, Segment::Vertical { .. }) => self.cross(other),
+            (Segment::Vertical { .. }, Segment::Horizontal { .. }) => other.cross(self),
+            _ => None,
+        }
+    }
 
-    loop {
-        current = Point {
-            x: current.x + step.x,
-            y: current.y + step.y,
-        };
-        if current == second {
-            break;
+    /// Crossing point of `self` (a horizontal segment) against `vertical`.
+    fn cross(&self, vertical: &Segment) -> Option<Point> {
+        if let (
+            Segment::Horizontal {
+                row,
+                col_start,
+                col_end,
+                ..
+            },
+            Segment::Vertical {
+                col,
+                row_start,
+                row_end,
+                ..
+            },
+        ) = (self, vertical)
+        {
+            if row_start <= row && row <= row_end && col_start <= col && col <= col_end {
+                Some(Point { x: *col, y: *row })
+            } else {
+                None
+            }
+        } else {
+            None
         }
-        points.push(current);
     }
 
-    points
+    /// Accumulated wire length at `point`, which must lie on this segment.
+    fn steps_to(&self, point: Point) -> u32 {
+        match *self {
+            Segment::Horizontal {
+                col_start,
+                steps_start,
+                steps_dir,
+                ..
+            } => (steps_start as i64 + (point.x - col_start) as i64 * steps_dir as i64) as u32,
+            Segment::Vertical {
+                row_start,
+                steps_start,
+                steps_dir,
+                ..
+            } => (steps_start as i64 + (point.y - row_start) as i64 * steps_dir as i64) as u32,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -120,14 +243,16 @@ enum Direction {
     Right,
 }
 
-impl From<char> for Direction {
-    fn from(chr: char) -> Self {
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(chr: char) -> Result<Self, Self::Error> {
         match chr {
-            'U' => Direction::Up,
-            'D' => Direction::Down,
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            _ => unimplemented!(),
+            'U' => Ok(Direction::Up),
+            'D' => Ok(Direction::Down),
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            other => Err(ParseError::UnknownDirection(other)),
         }
     }
 }
@@ -138,17 +263,23 @@ struct Move {
     distance: u32,
 }
 
-impl From<&str> for Move {
-    fn from(string: &str) -> Self {
-        Move {
-            direction: string.chars().next().unwrap().into(),
-            distance: string.get(1..).unwrap().parse().unwrap(),
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut chars = string.chars();
+        let direction = Direction::try_from(chars.next().ok_or(ParseError::Empty)?)?;
+
+        let distance = chars.as_str();
+        if distance.is_empty() {
+            return Err(ParseError::MissingDistance);
         }
+        let distance = distance.parse().map_err(ParseError::InvalidDistance)?;
+
+        Ok(Move { direction, distance })
     }
 }
 
-type Wire = Vec<Point>;
-
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 struct Point {
     x: i32,
@@ -202,63 +333,140 @@ impl Add<Move> for Point {
     }
 }
 
-/// Find intersections of wires. Intersection is guaranteed to be included as a Point in a wire
-/// vec as all lines are aligned along axis and all moves' distances are integer.
-fn find_intersections(left: &[Point], right: &[Point]) -> HashSet<Point> {
-    let left_set: HashSet<Point> = left.iter().cloned().collect();
-    let right_set: HashSet<Point> = right.iter().cloned().collect();
-    let mut intersections = left_set
-        .intersection(&right_set)
-        .cloned()
-        .collect::<HashSet<_>>();
-    intersections.remove(&Point { x: 0, y: 0 });
-    intersections
+/// A point where two segments from different wires cross, together with the combined number of
+/// steps both wires need to take to reach it.
+struct Crossing {
+    point: Point,
+    steps: u32,
 }
 
-/// Find closest to origin intersection of two wires.
-fn find_closest_intersection(left: &[Point], right: &[Point]) -> Option<Point> {
-    find_intersections(left, right).iter().min().cloned()
+/// Find every crossing between a pair of wires, excluding the shared origin.
+fn find_crossings(left: &[Segment], right: &[Segment]) -> Vec<Crossing> {
+    let origin = Point { x: 0, y: 0 };
+    left.iter()
+        .flat_map(|l| right.iter().map(move |r| (l, r)))
+        .filter_map(|(l, r)| l.collision(r).map(|point| (l, r, point)))
+        .filter(|&(_, _, point)| point != origin)
+        .map(|(l, r, point)| Crossing {
+            point,
+            steps: l.steps_to(point) + r.steps_to(point),
+        })
+        .collect()
 }
 
-/// Find intersection of wires that produces minimal delay. Sum of lengths of wires till this
-/// intersection should be minimal.
-fn find_minimal_step_intersection(left: &[Point], right: &[Point]) -> Option<u32> {
-    find_intersections(left, right)
-        .iter()
-        .map(|&point| length_to_point_in_wire(left, point) + length_to_point_in_wire(right, point))
+/// Find every crossing between any two distinct wires among `wires`.
+fn find_all_crossings(wires: &[&[Segment]]) -> Vec<Crossing> {
+    let mut crossings = Vec::new();
+    for i in 0..wires.len() {
+        for j in (i + 1)..wires.len() {
+            crossings.extend(find_crossings(wires[i], wires[j]));
+        }
+    }
+    crossings
+}
+
+/// Find intersections of wires. Only perpendicular segments are considered: two overlapping
+/// parallel runs are not reported as a crossing.
+fn find_intersections_all(wires: &[&[Segment]]) -> HashSet<Point> {
+    find_all_crossings(wires)
+        .into_iter()
+        .map(|crossing| crossing.point)
+        .collect()
+}
+
+/// Find the crossing closest to the origin across all pairs of wires.
+fn find_closest_intersection_all(wires: &[&[Segment]]) -> Option<Point> {
+    find_intersections_all(wires).into_iter().min()
+}
+
+/// Find the combined step count of the crossing that produces minimal delay across all pairs of
+/// wires: the smallest sum of lengths of the two wires till a crossing.
+fn find_minimal_step_intersection_all(wires: &[&[Segment]]) -> Option<u32> {
+    find_all_crossings(wires)
+        .into_iter()
+        .map(|crossing| crossing.steps)
         .min()
 }
 
-/// Compute length of a wire segment from origin to this point.
-fn length_to_point_in_wire(wire: &[Point], point: Point) -> u32 {
-    wire.iter().position(|&p| p == point).unwrap() as u32
+/// Find intersections of a pair of wires. Thin wrapper over [`find_intersections_all`]; only
+/// exercised by tests now that `main` drives the N-wire variant directly.
+#[cfg(test)]
+fn find_intersections(left: &[Segment], right: &[Segment]) -> HashSet<Point> {
+    find_intersections_all(&[left, right])
+}
+
+/// Find closest to origin intersection of two wires.
+#[cfg(test)]
+fn find_closest_intersection(left: &[Segment], right: &[Segment]) -> Option<Point> {
+    find_intersections(left, right).into_iter().min()
+}
+
+/// Find the combined step count of the intersection of two wires that produces minimal delay.
+/// Thin wrapper over
+/// [`find_minimal_step_intersection_all`].
+#[cfg(test)]
+fn find_minimal_step_intersection(left: &[Segment], right: &[Segment]) -> Option<u32> {
+    find_minimal_step_intersection_all(&[left, right])
+}
+
+#[cfg(test)]
+fn print_wires(wires: &[Wire]) {
+    for (index, wire) in wires.iter().enumerate() {
+        for segment in wire {
+            println!("wire {}: {:?}", index, segment);
+        }
+    }
 }
 
 #[test]
 fn test_find_closest_intersection() {
-    let cases = [
-        (
-            [
-                [Point { x: 0, y: 6 }, Point { x: 5, y: 0 }],
-                [Point { x: 5, y: 0 }, Point { x: 0, y: 6 }],
-            ],
-            Some(Point { x: 5, y: 0 }),
-        ),
-        (
-            [
-                [Point { x: 0, y: 6 }, Point { x: 5, y: 0 }],
-                [Point { x: 0, y: 6 }, Point { x: 5, y: 0 }],
-            ],
-            Some(Point { x: 5, y: 0 }),
-        ),
-    ];
+    let wires = parse_wires("R8,U5,L5,D3\nU7,R6,D4,L4").unwrap();
+    print_wires(&wires);
+    assert_eq!(
+        find_closest_intersection(&wires[0], &wires[1]),
+        Some(Point { x: 3, y: 3 })
+    );
 
-    for case in &cases {
-        assert_eq!(
-            find_closest_intersection(&case.0[0], &case.0[1]),
-            Some(Point { x: 5, y: 0 })
-        )
-    }
+    let wires = parse_wires("R2,U1\nU1,R1").unwrap();
+    assert_eq!(find_closest_intersection(&wires[0], &wires[1]), None);
+}
+
+#[test]
+fn test_find_intersections_across_many_wires() {
+    let wires = parse_wires("R8,U5,L5,D3\nU7,R6,D4,L4\nL3,D3").unwrap();
+    let wire_refs: Vec<&[Segment]> = wires.iter().map(Vec::as_slice).collect();
+
+    assert_eq!(
+        find_closest_intersection_all(&wire_refs),
+        Some(Point { x: 3, y: 3 })
+    );
+    assert_eq!(find_minimal_step_intersection_all(&wire_refs), Some(30));
+}
+
+#[test]
+fn test_move_from_str_errors() {
+    assert!(matches!("".parse::<Move>(), Err(ParseError::Empty)));
+    assert!(matches!(
+        "R".parse::<Move>(),
+        Err(ParseError::MissingDistance)
+    ));
+    assert!(matches!(
+        "Rabc".parse::<Move>(),
+        Err(ParseError::InvalidDistance(_))
+    ));
+    assert!(matches!(
+        "X5".parse::<Move>(),
+        Err(ParseError::UnknownDirection('X'))
+    ));
+}
+
+#[test]
+fn test_parse_wires_errors() {
+    assert!(matches!(parse_wires(""), Err(ParseError::Empty)));
+    assert!(matches!(
+        parse_wires("R8\nX5"),
+        Err(ParseError::UnknownDirection('X'))
+    ));
 }
 
 #[test]
@@ -301,31 +509,63 @@ fn test_point_ordering() {
 
 #[test]
 fn test_wire_parsing() {
-    let text = "R2,U2,L3,D1\nL1,U2,R3".to_owned();
-    let wires = parse_wires(text);
+    let wires = parse_wires("R2,U2,L3,D1\nL1,U2,R3").unwrap();
     print_wires(&wires);
     assert_eq!(
         wires,
         [
             vec![
-                Point { x: 0, y: 0 },
-                Point { x: 1, y: 0 },
-                Point { x: 2, y: 0 },
-                Point { x: 2, y: 1 },
-                Point { x: 2, y: 2 },
-                Point { x: 1, y: 2 },
-                Point { x: 0, y: 2 },
-                Point { x: -1, y: 2 },
-                Point { x: -1, y: 1 },
+                Segment::Horizontal {
+                    row: 0,
+                    col_start: 0,
+                    col_end: 2,
+                    steps_start: 0,
+                    steps_dir: 1,
+                },
+                Segment::Vertical {
+                    col: 2,
+                    row_start: 0,
+                    row_end: 2,
+                    steps_start: 2,
+                    steps_dir: 1,
+                },
+                Segment::Horizontal {
+                    row: 2,
+                    col_start: -1,
+                    col_end: 2,
+                    steps_start: 7,
+                    steps_dir: -1,
+                },
+                Segment::Vertical {
+                    col: -1,
+                    row_start: 1,
+                    row_end: 2,
+                    steps_start: 8,
+                    steps_dir: -1,
+                },
             ],
             vec![
-                Point { x: 0, y: 0 },
-                Point { x: -1, y: 0 },
-                Point { x: -1, y: 1 },
-                Point { x: -1, y: 2 },
-                Point { x: 0, y: 2 },
-                Point { x: 1, y: 2 },
-                Point { x: 2, y: 2 },
+                Segment::Horizontal {
+                    row: 0,
+                    col_start: -1,
+                    col_end: 0,
+                    steps_start: 1,
+                    steps_dir: -1,
+                },
+                Segment::Vertical {
+                    col: -1,
+                    row_start: 0,
+                    row_end: 2,
+                    steps_start: 1,
+                    steps_dir: 1,
+                },
+                Segment::Horizontal {
+                    row: 2,
+                    col_start: -1,
+                    col_end: 2,
+                    steps_start: 3,
+                    steps_dir: 1,
+                },
             ]
         ]
     )
@@ -334,86 +574,44 @@ fn test_wire_parsing() {
 #[test]
 fn test_wire_crossing_distance() {
     let cases = vec![
+        ("R8,U5,L5,D3\nU7,R6,D4,L4", 6),
         (
-            vec![
-                vec![Point { x: 0, y: 5 }, Point { x: 0, y: 6 }],
-                vec![Point { x: 0, y: 6 }, Point { x: 1, y: 6 }],
-            ],
-            6,
-        ),
-        (
-            parse_wires(
-                "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83".to_owned(),
-            ),
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83",
             159,
         ),
         (
-            parse_wires(
-                "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"
-                    .to_owned(),
-            ),
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
             135,
         ),
     ];
 
-    for case in cases {
-        let wires = case.0;
-        let distance = case.1;
+    for (text, distance) in cases {
+        let wires = parse_wires(text).unwrap();
         print_wires(&wires);
         let intersection = find_closest_intersection(&wires[0], &wires[1]);
         assert_eq!(intersection.unwrap().distance_from_origin(), distance);
     }
-
-    assert_eq!(
-        find_closest_intersection(
-            &[Point { x: 0, y: 0 }, Point { x: 0, y: 1 }],
-            &[Point { x: 1, y: 2 }, Point { x: 2, y: 2 }]
-        ),
-        None
-    );
 }
 
 #[test]
-fn test_all_points_between() {
+fn test_wire_minimal_step_intersection() {
     let cases = vec![
-        ((Point { x: 0, y: 0 }, Point { x: 1, y: 0 }), vec![]),
+        ("R8,U5,L5,D3\nU7,R6,D4,L4", 30),
         (
-            (Point { x: 0, y: 0 }, Point { x: 2, y: 0 }),
-            vec![Point { x: 1, y: 0 }],
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83",
+            610,
         ),
         (
-            (Point { x: -1, y: 0 }, Point { x: 1, y: 0 }),
-            vec![Point { x: 0, y: 0 }],
-        ),
-        ((Point { x: -1, y: 0 }, Point { x: -2, y: 0 }), vec![]),
-        (
-            (Point { x: -1, y: 0 }, Point { x: -3, y: 0 }),
-            vec![Point { x: -2, y: 0 }],
-        ),
-        ((Point { x: 1, y: 1 }, Point { x: 1, y: 1 }), vec![]),
-        (
-            (Point { x: 5, y: 6 }, Point { x: 5, y: 10 }),
-            vec![
-                Point { x: 5, y: 7 },
-                Point { x: 5, y: 8 },
-                Point { x: 5, y: 9 },
-            ],
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+            410,
         ),
     ];
 
-    for case in cases {
-        assert_eq!(all_points_between(case.0.0, case.0.1), case.1, "\nfailed case {:?}", case);
-    }
-}
-
-#[test]
-#[should_panic]
-fn test_all_points_between_panics_when_points_are_not_on_the_same_axis() {
-    all_points_between(Point { x: 0, y: 1 }, Point { x: 1, y: 2 });
-}
-
-fn print_wires(wires: &[Vec<Point>]) {
-    for (p1, p2) in wires[0].iter().zip(wires[1].iter()) {
-        println!("{:5?}       {:5?}", p1, p2);
+    for (text, steps) in cases {
+        let wires = parse_wires(text).unwrap();
+        assert_eq!(
+            find_minimal_step_intersection(&wires[0], &wires[1]),
+            Some(steps)
+        );
     }
 }